@@ -30,6 +30,26 @@ enum Command {
 
         #[clap(value_parser)]
         output_file: Option<String>,
+
+        /// Treat `message` as a path to a file and store its base64-encoded
+        /// bytes instead of the literal string, so binary payloads round-trip.
+        #[clap(long)]
+        base64: bool,
+
+        /// Split the payload into fragments of at most this many bytes,
+        /// each written as its own sequenced chunk. Defaults to one chunk.
+        #[clap(long)]
+        chunk_size: Option<usize>,
+
+        /// Author to record in an accompanying manifest chunk. Omit to
+        /// skip writing a manifest.
+        #[clap(long)]
+        author: Option<String>,
+
+        /// Content-type to record in the manifest chunk. Only used when
+        /// `--author` is set.
+        #[clap(long)]
+        content_type: Option<String>,
     },
 
     Decode {
@@ -38,6 +58,24 @@ enum Command {
 
         #[clap(value_parser)]
         chunk_type: String,
+
+        /// Look up the base64-marked variant of `chunk_type`, required to
+        /// find a chunk that was written with `encode --base64` (which
+        /// stores it under the marked type, not `chunk_type` as typed).
+        /// Once found, the chunk's own marker bit, not this flag, decides
+        /// whether the payload is base64-decoded.
+        #[clap(long)]
+        base64: bool,
+
+        /// Where to write the decoded bytes when the chunk found is
+        /// base64-marked (see `--base64`). Defaults to stdout.
+        #[clap(long)]
+        output_file: Option<String>,
+
+        /// Skip CRC verification, trusting that `file_path` was produced by
+        /// this program. Faster on large files.
+        #[clap(long)]
+        trusted: bool,
     },
 
     Remove {
@@ -51,6 +89,11 @@ enum Command {
     Print {
         #[clap(value_parser)]
         file_path: String,
+
+        /// Skip CRC verification, trusting that `file_path` was produced by
+        /// this program. Faster on large files.
+        #[clap(long)]
+        trusted: bool,
     },
 }
 
@@ -62,16 +105,32 @@ impl Command {
                 chunk_type,
                 message,
                 output_file,
-            } => encode(file_path, chunk_type, message, output_file),
+                base64,
+                chunk_size,
+                author,
+                content_type,
+            } => encode(
+                file_path,
+                chunk_type,
+                message,
+                output_file,
+                base64,
+                chunk_size,
+                author,
+                content_type,
+            ),
             Command::Decode {
                 file_path,
                 chunk_type,
-            } => decode(file_path, chunk_type).map(|msg| println!("{}", msg)),
+                base64,
+                output_file,
+                trusted,
+            } => decode(file_path, chunk_type, base64, output_file, trusted),
             Command::Remove {
                 file_path,
                 chunk_type,
             } => remove(file_path, chunk_type).map(|msg| println!("{}", msg)),
-            Command::Print { file_path } => print(file_path),
+            Command::Print { file_path, trusted } => print(file_path, trusted),
         }
     }
 }
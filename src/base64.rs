@@ -0,0 +1,122 @@
+//! A small, dependency-free base64 (standard alphabet, `=` padding) codec.
+//!
+//! This exists so binary payloads can round-trip through a PNG chunk without
+//! pulling in a crate: `encode` turns arbitrary bytes into an ASCII string
+//! safe to store as chunk data, `decode` reverses it.
+
+use crate::Result;
+use anyhow::bail;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        let group = ((b0 as u32) << 16) | ((b1 as u32) << 8) | (b2 as u32);
+
+        let indices = [
+            (group >> 18) & 0x3f,
+            (group >> 12) & 0x3f,
+            (group >> 6) & 0x3f,
+            group & 0x3f,
+        ];
+
+        out.push(ALPHABET[indices[0] as usize] as char);
+        out.push(ALPHABET[indices[1] as usize] as char);
+        out.push(if chunk.len() > 1 {
+            ALPHABET[indices[2] as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            ALPHABET[indices[3] as usize] as char
+        } else {
+            '='
+        });
+    }
+
+    out
+}
+
+fn table_index(c: u8) -> Result<u32> {
+    ALPHABET
+        .iter()
+        .position(|&a| a == c)
+        .map(|i| i as u32)
+        .ok_or_else(|| anyhow::anyhow!("'{}' is not a valid base64 character", c as char))
+}
+
+pub fn decode(input: &str) -> Result<Vec<u8>> {
+    if !input.len().is_multiple_of(4) {
+        bail!("base64 input length must be a multiple of 4")
+    }
+
+    let mut out = Vec::with_capacity(input.len() / 4 * 3);
+
+    for group in input.as_bytes().chunks(4) {
+        let padding = group.iter().filter(|&&b| b == b'=').count();
+
+        let mut indices = [0u32; 4];
+        for (i, &b) in group.iter().enumerate() {
+            indices[i] = if b == b'=' { 0 } else { table_index(b)? };
+        }
+
+        let value = (indices[0] << 18) | (indices[1] << 12) | (indices[2] << 6) | indices[3];
+
+        out.push((value >> 16) as u8);
+        if padding < 2 {
+            out.push((value >> 8) as u8);
+        }
+        if padding < 1 {
+            out.push(value as u8);
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_empty() {
+        assert_eq!(encode(b""), "");
+    }
+
+    #[test]
+    fn test_encode_no_padding() {
+        assert_eq!(encode(b"Man"), "TWFu");
+    }
+
+    #[test]
+    fn test_encode_one_padding_char() {
+        assert_eq!(encode(b"Ma"), "TWE=");
+    }
+
+    #[test]
+    fn test_encode_two_padding_chars() {
+        assert_eq!(encode(b"M"), "TQ==");
+    }
+
+    #[test]
+    fn test_decode_roundtrip() {
+        let data = b"arbitrary binary \x00\x01\xffdata";
+        assert_eq!(decode(&encode(data)).unwrap(), data);
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_length() {
+        assert!(decode("TWE").is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_char() {
+        assert!(decode("T!E=").is_err());
+    }
+}
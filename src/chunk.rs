@@ -1,13 +1,13 @@
 use crate::chunk_type::ChunkType;
 use crate::Result;
-use anyhow::{anyhow, bail, Error};
+use anyhow::{anyhow, bail, Context, Error};
 use std::fmt::Display;
+use std::io::{Cursor, Read};
 
 #[derive(Debug)]
 pub struct Chunk {
     chunk_type: ChunkType,
     pub data: Vec<u8>,
-    pub bytes: Vec<u8>,
 }
 
 impl TryFrom<&[u8]> for Chunk {
@@ -15,26 +15,7 @@ impl TryFrom<&[u8]> for Chunk {
 
     // 0, 0, 0, 1, 115, 82, 71, 66, 0, 174, 206, 28, 233,
     fn try_from(value: &[u8]) -> Result<Self> {
-        let mut chunk_bytes = [0; 4];
-        chunk_bytes.clone_from_slice(&value[4..8]);
-        let chunk_type = ChunkType::try_from(chunk_bytes)?;
-
-        let chunk = Self {
-            chunk_type,
-            data: value[8..value.len() - 4].to_vec(),
-            bytes: value.to_vec(),
-        };
-
-        let mut crc = [0; 4];
-        crc.clone_from_slice(&value[value.len() - 4..]);
-
-        let crc_bytes: [u8; 4] = chunk.crc().to_be_bytes();
-
-        if crc != crc_bytes {
-            bail!("crc and calculated crc don't match")
-        }
-
-        Ok(chunk)
+        Self::parse(value, true)
     }
 }
 
@@ -46,11 +27,51 @@ impl Display for Chunk {
 
 impl Chunk {
     pub fn new(chunk_type: ChunkType, data: Vec<u8>) -> Self {
-        Self {
-            chunk_type,
-            data: data.clone(),
-            bytes: data,
+        Self { chunk_type, data }
+    }
+
+    /// Parses a chunk without recomputing its CRC, trusting that `value`
+    /// was produced by this program rather than read from an untrusted
+    /// source. Skips straight to the fast path used by [`Png::from_trusted_bytes`].
+    ///
+    /// [`Png::from_trusted_bytes`]: crate::png::Png::from_trusted_bytes
+    pub fn try_from_trusted(value: &[u8]) -> Result<Self> {
+        Self::parse(value, false)
+    }
+
+    fn parse(value: &[u8], verify_crc: bool) -> Result<Self> {
+        let mut reader = Cursor::new(value);
+
+        let mut length_bytes = [0; 4];
+        reader
+            .read_exact(&mut length_bytes)
+            .context("unexpected end of input: missing chunk length")?;
+        let length = u32::from_be_bytes(length_bytes) as usize;
+
+        let mut type_bytes = [0; 4];
+        reader
+            .read_exact(&mut type_bytes)
+            .context("unexpected end of input: missing chunk type")?;
+        let chunk_type = ChunkType::try_from(type_bytes)?;
+
+        let mut data = vec![0; length];
+        reader
+            .read_exact(&mut data)
+            .context("unexpected end of input: chunk data shorter than declared length")?;
+
+        let mut crc_bytes = [0; 4];
+        reader
+            .read_exact(&mut crc_bytes)
+            .context("unexpected end of input: missing chunk crc")?;
+        let crc = u32::from_be_bytes(crc_bytes);
+
+        let chunk = Self { chunk_type, data };
+
+        if verify_crc && crc != chunk.crc() {
+            bail!("crc and calculated crc don't match")
         }
+
+        Ok(chunk)
     }
 
     pub fn length(&self) -> u32 {
@@ -76,6 +97,7 @@ impl Chunk {
         crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC).checksum(&input)
     }
 
+    #[cfg(test)]
     pub fn data_as_string(&self) -> Result<String> {
         std::str::from_utf8(self.data())
             .map(|s| s.to_string())
@@ -204,6 +226,38 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    fn test_chunk_from_truncated_bytes_does_not_panic() {
+        let chunk_data: Vec<u8> = vec![0, 0, 0, 42, 82, 117];
+
+        let chunk = Chunk::try_from(chunk_data.as_ref());
+
+        assert!(chunk.is_err());
+    }
+
+    #[test]
+    fn test_chunk_from_bytes_with_wrong_declared_length() {
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656334;
+
+        // Declares a length far larger than the data that actually follows.
+        let data_length: u32 = 4242;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::try_from(chunk_data.as_ref());
+
+        assert!(chunk.is_err());
+    }
+
     #[test]
     pub fn test_chunk_trait_impls() {
         let data_length: u32 = 42;
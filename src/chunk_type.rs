@@ -2,7 +2,7 @@ use crate::Result;
 use anyhow::{bail, Error};
 use std::{fmt::Display, str::FromStr};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChunkType([u8; 4]);
 
 impl TryFrom<[u8; 4]> for ChunkType {
@@ -60,6 +60,19 @@ impl ChunkType {
         is_lowercase(self.0[3])
     }
 
+    /// pngme convention, not part of the PNG spec: a lowercase reserved byte
+    /// marks the chunk's data as base64-encoded rather than raw bytes.
+    pub fn is_base64_encoded(&self) -> bool {
+        is_lowercase(self.0[2])
+    }
+
+    /// Returns a copy of this `ChunkType` with the base64 marker bit set.
+    pub fn as_base64_encoded(&self) -> ChunkType {
+        let mut bytes = self.0;
+        bytes[2] = bytes[2].to_ascii_lowercase();
+        ChunkType(bytes)
+    }
+
     /// Checks if ChunkType is valid or not.
     /// For convenience in description and in examining PNG files, type codes are
     /// restricted to consist of uppercase and lowercase ASCII letters (A-Z and a-z, or 65-90 and 97-122 decimal).
@@ -160,6 +173,15 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    pub fn test_chunk_type_is_base64_encoded() {
+        let chunk = ChunkType::from_str("RuSt").unwrap();
+        assert!(!chunk.is_base64_encoded());
+
+        let chunk = chunk.as_base64_encoded();
+        assert!(chunk.is_base64_encoded());
+    }
+
     #[test]
     pub fn test_chunk_type_string() {
         let chunk = ChunkType::from_str("RuSt").unwrap();
@@ -1,22 +1,90 @@
-use anyhow::{anyhow, Context, Ok};
+use anyhow::{bail, Context, Ok};
 use std::{
     fs::{read, read_to_string, write, File},
+    io::Write as _,
     str::FromStr,
 };
 
-use crate::{chunk::Chunk, chunk_type::ChunkType, png::Png, Result};
+use crate::{
+    base64,
+    chunk::Chunk,
+    chunk_type::ChunkType,
+    manifest::{self, Manifest},
+    png::Png,
+    Result,
+};
+
+/// A fragment's data is prefixed with the total fragment count and its own
+/// zero-based index, both big-endian `u32`s, so `decode` can reassemble
+/// fragments written out of order and detect a missing one.
+const FRAGMENT_HEADER_LEN: usize = 8;
+
+fn fragment_header(total: u32, index: u32) -> [u8; FRAGMENT_HEADER_LEN] {
+    let mut header = [0; FRAGMENT_HEADER_LEN];
+    header[..4].copy_from_slice(&total.to_be_bytes());
+    header[4..].copy_from_slice(&index.to_be_bytes());
+    header
+}
+
+fn parse_fragment(data: &[u8]) -> Result<(u32, u32, &[u8])> {
+    if data.len() < FRAGMENT_HEADER_LEN {
+        bail!("fragment data is shorter than its 8-byte header")
+    }
 
+    let total = u32::from_be_bytes(data[0..4].try_into().unwrap());
+    let index = u32::from_be_bytes(data[4..8].try_into().unwrap());
+    Ok((total, index, &data[FRAGMENT_HEADER_LEN..]))
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn encode(
     file_path: String,
     chunk_type: String,
     message: String,
     output_file: Option<String>,
+    base64: bool,
+    chunk_size: Option<usize>,
+    author: Option<String>,
+    content_type: Option<String>,
 ) -> Result<()> {
     let file_bytes = read(&file_path)?;
     let mut png = Png::try_from(&file_bytes[..])?;
-    let chunk = Chunk::new(ChunkType::from_str(&chunk_type)?, message.into_bytes());
 
-    png.append_chunk(chunk);
+    let (chunk_type, payload) = if base64 {
+        let raw = read(&message).context("reading file to embed as base64")?;
+        (
+            ChunkType::from_str(&chunk_type)?.as_base64_encoded(),
+            self::base64::encode(&raw).into_bytes(),
+        )
+    } else {
+        (ChunkType::from_str(&chunk_type)?, message.into_bytes())
+    };
+
+    let fragment_size = chunk_size.unwrap_or(payload.len()).max(1);
+    let fragments: Vec<&[u8]> = if payload.is_empty() {
+        vec![&payload[..]]
+    } else {
+        payload.chunks(fragment_size).collect()
+    };
+    let total = fragments.len() as u32;
+
+    for (index, fragment) in fragments.into_iter().enumerate() {
+        let mut data = fragment_header(total, index as u32).to_vec();
+        data.extend_from_slice(fragment);
+        png.append_chunk(Chunk::new(chunk_type, data));
+    }
+
+    if let Some(author) = author {
+        let manifest = Manifest::new(
+            author,
+            generalized_time_now(),
+            content_type.map(String::into_bytes),
+        );
+        png.append_chunk(Chunk::new(
+            ChunkType::from_str(manifest::CHUNK_TYPE)?,
+            manifest.to_der(),
+        ));
+    }
 
     let write_path = output_file.unwrap_or(file_path);
 
@@ -24,13 +92,139 @@ pub fn encode(
     Ok(())
 }
 
-pub fn decode(file_path: String, chunk_type: String) -> Result<String> {
+/// The current UTC time formatted as a DER `GeneralizedTime`
+/// (`YYYYMMDDHHMMSSZ`), computed without a time/date crate.
+fn generalized_time_now() -> String {
+    let since_epoch = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+
+    let total_secs = since_epoch.as_secs() as i64;
+    let days = total_secs.div_euclid(86400);
+    let secs_of_day = total_secs.rem_euclid(86400);
+
+    let (year, month, day) = civil_from_days(days);
+    let hour = secs_of_day / 3600;
+    let minute = (secs_of_day % 3600) / 60;
+    let second = secs_of_day % 60;
+
+    format!(
+        "{:04}{:02}{:02}{:02}{:02}{:02}Z",
+        year, month, day, hour, minute, second
+    )
+}
+
+/// Howard Hinnant's `civil_from_days`: converts a day count since the Unix
+/// epoch into a proleptic-Gregorian (year, month, day).
+fn civil_from_days(days_since_epoch: i64) -> (i64, u32, u32) {
+    let z = days_since_epoch + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = (z - era * 146097) as u64;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let year = year_of_era as i64 + era * 400;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+pub fn decode(
+    file_path: String,
+    chunk_type: String,
+    base64: bool,
+    output_file: Option<String>,
+    trusted: bool,
+) -> Result<()> {
     let file_bytes = read(&file_path)?;
-    let png = Png::try_from(&file_bytes[..])?;
+    let png = if trusted {
+        Png::from_trusted_bytes(&file_bytes[..])?
+    } else {
+        Png::from_untrusted_bytes(&file_bytes[..])?
+    };
+
+    print_manifest(&png);
+
+    let parsed_chunk_type = ChunkType::from_str(&chunk_type)?;
+    let lookup_chunk_type = if base64 {
+        parsed_chunk_type.as_base64_encoded()
+    } else {
+        parsed_chunk_type
+    };
+    // `--base64` (or passing the already-marked type, e.g. `Rust` instead
+    // of `RuSt`) is required to find the chunk at all, since the marker bit
+    // changes the type it was stored under. Once found, the marker bit
+    // itself (not the flag) decides whether the reassembled payload gets
+    // base64-decoded.
+    let base64 = lookup_chunk_type.is_base64_encoded();
+    let chunk_type = lookup_chunk_type.to_string();
+
+    let chunks = png.chunks_by_type(&chunk_type);
+    if chunks.is_empty() {
+        bail!(
+            "no chunks of type {} found (if this was encoded with --base64, pass --base64 on decode too)",
+            chunk_type
+        )
+    }
+
+    let mut fragments: Vec<(u32, &[u8])> = chunks
+        .iter()
+        .map(|chunk| parse_fragment(chunk.data()))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .map(|(total, index, data)| {
+            if total as usize != chunks.len() {
+                bail!(
+                    "expected {} fragments of chunk type {}, found {}",
+                    total,
+                    chunk_type,
+                    chunks.len()
+                )
+            }
+            Ok((index, data))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    fragments.sort_by_key(|(index, _)| *index);
+    for pair in fragments.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            bail!(
+                "fragment set for chunk type {} has duplicate fragment index {}",
+                chunk_type,
+                pair[0].0
+            )
+        }
+    }
+    for (expected, (index, _)) in fragments.iter().enumerate() {
+        if *index != expected as u32 {
+            bail!(
+                "fragment set for chunk type {} is incomplete: missing index {}",
+                chunk_type,
+                expected
+            )
+        }
+    }
 
-    png.chunk_by_type(&chunk_type)
-        .context("not chunk type")?
-        .data_as_string()
+    let payload: Vec<u8> = fragments
+        .into_iter()
+        .flat_map(|(_, data)| data.iter().copied())
+        .collect();
+
+    if !base64 {
+        println!("{}", std::str::from_utf8(&payload)?);
+        return Ok(());
+    }
+
+    let decoded = self::base64::decode(std::str::from_utf8(&payload)?.trim_end())?;
+
+    match output_file {
+        Some(path) => write(path, decoded)?,
+        None => std::io::stdout().write_all(&decoded)?,
+    }
+
+    Ok(())
 }
 
 pub fn remove(file_path: String, chunk_type: String) -> Result<String> {
@@ -44,11 +238,39 @@ pub fn remove(file_path: String, chunk_type: String) -> Result<String> {
     Ok(removed)
 }
 
-pub fn print(file_path: String) -> Result<()> {
+pub fn print(file_path: String, trusted: bool) -> Result<()> {
     let file_bytes = read(&file_path)?;
-    let png = Png::try_from(&file_bytes[..])?;
+    let png = if trusted {
+        Png::from_trusted_bytes(&file_bytes[..])?
+    } else {
+        Png::from_untrusted_bytes(&file_bytes[..])?
+    };
 
     print!("{:?}", png);
+    print_manifest(&png);
 
     Ok(())
 }
+
+/// Prints the decoded fields of the manifest chunk, if the PNG carries one.
+///
+/// Written to stderr so it never contaminates a raw payload written to
+/// stdout (e.g. `decode --base64` with no `--output-file`).
+fn print_manifest(png: &Png) {
+    let Some(chunk) = png.chunk_by_type(manifest::CHUNK_TYPE) else {
+        return;
+    };
+
+    match Manifest::from_der(chunk.data()) {
+        std::result::Result::Ok(manifest) => {
+            eprintln!("author: {}", manifest.author);
+            eprintln!("timestamp: {}", manifest.timestamp);
+            if let Some(content_type) = &manifest.content_type {
+                eprintln!("content-type: {}", String::from_utf8_lossy(content_type));
+            }
+        }
+        std::result::Result::Err(e) => {
+            eprintln!("manifest chunk present but could not be parsed: {}", e)
+        }
+    }
+}
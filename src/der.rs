@@ -0,0 +1,132 @@
+//! A minimal tag-length-value (TLV) reader/writer for the handful of DER
+//! (ASN.1) types the manifest chunk needs. Not a general DER implementation
+//! -- just enough to encode/decode a `SEQUENCE` of a `UTF8String`, a
+//! `GeneralizedTime` and an optional `OCTET STRING`.
+//!
+//! Each element is `[tag byte][length][value]`. Lengths under 128 fit in a
+//! single byte; longer ones use the long form: `0x80 | num_length_bytes`
+//! followed by the length itself, big-endian.
+
+use crate::Result;
+use anyhow::{bail, Context};
+use std::io::{Cursor, Read};
+
+pub const TAG_UTF8_STRING: u8 = 0x0c;
+pub const TAG_GENERALIZED_TIME: u8 = 0x18;
+pub const TAG_OCTET_STRING: u8 = 0x04;
+pub const TAG_SEQUENCE: u8 = 0x30;
+
+pub fn write_tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    out.extend(write_length(value.len()));
+    out.extend_from_slice(value);
+    out
+}
+
+fn write_length(len: usize) -> Vec<u8> {
+    if len < 128 {
+        return vec![len as u8];
+    }
+
+    let significant_bytes: Vec<u8> = len
+        .to_be_bytes()
+        .iter()
+        .skip_while(|&&b| b == 0)
+        .copied()
+        .collect();
+
+    let mut out = vec![0x80 | significant_bytes.len() as u8];
+    out.extend(significant_bytes);
+    out
+}
+
+pub fn read_tlv(reader: &mut Cursor<&[u8]>) -> Result<(u8, Vec<u8>)> {
+    let mut tag = [0; 1];
+    reader
+        .read_exact(&mut tag)
+        .context("unexpected end of input: missing TLV tag")?;
+
+    let length = read_length(reader)?;
+
+    let mut value = vec![0; length];
+    reader
+        .read_exact(&mut value)
+        .context("unexpected end of input: TLV value shorter than its declared length")?;
+
+    Ok((tag[0], value))
+}
+
+fn read_length(reader: &mut Cursor<&[u8]>) -> Result<usize> {
+    let mut first_byte = [0; 1];
+    reader
+        .read_exact(&mut first_byte)
+        .context("unexpected end of input: missing TLV length")?;
+
+    if first_byte[0] & 0x80 == 0 {
+        return Ok(first_byte[0] as usize);
+    }
+
+    let num_length_bytes = (first_byte[0] & 0x7f) as usize;
+    if num_length_bytes == 0 || num_length_bytes > std::mem::size_of::<usize>() {
+        bail!("unsupported long-form TLV length encoding")
+    }
+
+    let mut length_bytes = vec![0; num_length_bytes];
+    reader
+        .read_exact(&mut length_bytes)
+        .context("unexpected end of input: truncated long-form TLV length")?;
+
+    Ok(length_bytes
+        .iter()
+        .fold(0usize, |length, &byte| (length << 8) | byte as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_tlv_short_length() {
+        let tlv = write_tlv(TAG_UTF8_STRING, b"hi");
+        assert_eq!(tlv, vec![TAG_UTF8_STRING, 2, b'h', b'i']);
+    }
+
+    #[test]
+    fn test_write_tlv_long_length() {
+        let value = vec![0u8; 200];
+        let tlv = write_tlv(TAG_OCTET_STRING, &value);
+        assert_eq!(tlv[0], TAG_OCTET_STRING);
+        assert_eq!(tlv[1], 0x81);
+        assert_eq!(tlv[2], 200);
+    }
+
+    #[test]
+    fn test_read_tlv_roundtrip_short_length() {
+        let tlv = write_tlv(TAG_UTF8_STRING, b"hello");
+        let mut reader = Cursor::new(tlv.as_slice());
+
+        let (tag, value) = read_tlv(&mut reader).unwrap();
+        assert_eq!(tag, TAG_UTF8_STRING);
+        assert_eq!(value, b"hello");
+    }
+
+    #[test]
+    fn test_read_tlv_roundtrip_long_length() {
+        let value = vec![7u8; 300];
+        let tlv = write_tlv(TAG_OCTET_STRING, &value);
+        let mut reader = Cursor::new(tlv.as_slice());
+
+        let (tag, read_value) = read_tlv(&mut reader).unwrap();
+        assert_eq!(tag, TAG_OCTET_STRING);
+        assert_eq!(read_value, value);
+    }
+
+    #[test]
+    fn test_read_tlv_truncated_value_errors() {
+        let mut bytes = write_tlv(TAG_UTF8_STRING, b"hello");
+        bytes.truncate(bytes.len() - 2);
+        let mut reader = Cursor::new(bytes.as_slice());
+
+        assert!(read_tlv(&mut reader).is_err());
+    }
+}
@@ -1,15 +1,18 @@
 use clap::Parser;
 
 mod args;
+mod base64;
 mod chunk;
 mod chunk_type;
 mod commands;
+mod der;
+mod manifest;
 mod png;
 
 pub type Result<T> = std::result::Result<T, anyhow::Error>;
 
 fn main() -> Result<()> {
     let cli = args::Arguments::parse();
-    println!("{:?}", cli);
+    eprintln!("{:?}", cli);
     cli.run()
 }
@@ -0,0 +1,112 @@
+//! The optional manifest chunk: a small ASN.1 `SEQUENCE` attaching metadata
+//! (author, creation time, content type) to a hidden message, since a
+//! chunk's `data` is otherwise just an opaque byte blob.
+
+use crate::der::{self, TAG_GENERALIZED_TIME, TAG_OCTET_STRING, TAG_SEQUENCE, TAG_UTF8_STRING};
+use crate::Result;
+use anyhow::{bail, Context};
+use std::io::Cursor;
+
+/// Chunk type conventionally used to carry a [`Manifest`].
+pub const CHUNK_TYPE: &str = "maNf";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Manifest {
+    pub author: String,
+    /// `GeneralizedTime`, formatted `YYYYMMDDHHMMSSZ`.
+    pub timestamp: String,
+    pub content_type: Option<Vec<u8>>,
+}
+
+impl Manifest {
+    pub fn new(author: String, timestamp: String, content_type: Option<Vec<u8>>) -> Self {
+        Self {
+            author,
+            timestamp,
+            content_type,
+        }
+    }
+
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut body = der::write_tlv(TAG_UTF8_STRING, self.author.as_bytes());
+        body.extend(der::write_tlv(
+            TAG_GENERALIZED_TIME,
+            self.timestamp.as_bytes(),
+        ));
+        if let Some(content_type) = &self.content_type {
+            body.extend(der::write_tlv(TAG_OCTET_STRING, content_type));
+        }
+
+        der::write_tlv(TAG_SEQUENCE, &body)
+    }
+
+    pub fn from_der(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Cursor::new(bytes);
+        let (tag, body) = der::read_tlv(&mut reader)?;
+        if tag != TAG_SEQUENCE {
+            bail!("manifest is not a DER SEQUENCE")
+        }
+
+        let mut body_reader = Cursor::new(body.as_slice());
+
+        let (tag, author) = der::read_tlv(&mut body_reader)?;
+        if tag != TAG_UTF8_STRING {
+            bail!("manifest author must be a UTF8String")
+        }
+        let author = String::from_utf8(author).context("manifest author is not valid UTF-8")?;
+
+        let (tag, timestamp) = der::read_tlv(&mut body_reader)?;
+        if tag != TAG_GENERALIZED_TIME {
+            bail!("manifest timestamp must be a GeneralizedTime")
+        }
+        let timestamp =
+            String::from_utf8(timestamp).context("manifest timestamp is not valid UTF-8")?;
+
+        let content_type = match der::read_tlv(&mut body_reader) {
+            Ok((tag, value)) if tag == TAG_OCTET_STRING => Some(value),
+            Ok((tag, _)) => bail!("unexpected manifest field with tag {:#x}", tag),
+            Err(_) => None,
+        };
+
+        Ok(Self {
+            author,
+            timestamp,
+            content_type,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_roundtrip_without_content_type() {
+        let manifest = Manifest::new("Ferris".to_string(), "20260729120000Z".to_string(), None);
+
+        let der = manifest.to_der();
+        let decoded = Manifest::from_der(&der).unwrap();
+
+        assert_eq!(manifest, decoded);
+    }
+
+    #[test]
+    fn test_manifest_roundtrip_with_content_type() {
+        let manifest = Manifest::new(
+            "Ferris".to_string(),
+            "20260729120000Z".to_string(),
+            Some(b"text/plain".to_vec()),
+        );
+
+        let der = manifest.to_der();
+        let decoded = Manifest::from_der(&der).unwrap();
+
+        assert_eq!(manifest, decoded);
+    }
+
+    #[test]
+    fn test_manifest_from_der_rejects_non_sequence() {
+        let tlv = der::write_tlv(TAG_UTF8_STRING, b"not a manifest");
+        assert!(Manifest::from_der(&tlv).is_err());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::chunk::Chunk;
+use crate::Result;
+use anyhow::bail;
+use std::fmt::Display;
+
+const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+#[derive(Debug)]
+pub struct Png {
+    chunks: Vec<Chunk>,
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_untrusted_bytes(bytes)
+    }
+}
+
+impl Display for Png {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("{:?}", self))
+    }
+}
+
+impl Png {
+    #[cfg(test)]
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Self {
+        Self { chunks }
+    }
+
+    /// Strict parsing for PNGs from an untrusted source: the 8-byte
+    /// signature must match exactly, every chunk's CRC is recomputed and
+    /// checked, `IHDR` must be the first chunk and `IEND` the last.
+    pub fn from_untrusted_bytes(bytes: &[u8]) -> Result<Self> {
+        let rest = Self::split_header(bytes)?;
+        let chunks = Self::parse_chunks(rest, true)?;
+
+        match chunks.first() {
+            Some(chunk) if chunk.chunk_type().to_string() == "IHDR" => {}
+            _ => bail!("first chunk must be IHDR"),
+        }
+        match chunks.last() {
+            Some(chunk) if chunk.chunk_type().to_string() == "IEND" => {}
+            _ => bail!("last chunk must be IEND"),
+        }
+
+        Ok(Self { chunks })
+    }
+
+    /// Fast-path parsing for PNGs this program produced itself: skips CRC
+    /// recomputation and the `IHDR`/`IEND` ordering check, so it's suited
+    /// to large files where re-CRCing every chunk dominates runtime.
+    pub fn from_trusted_bytes(bytes: &[u8]) -> Result<Self> {
+        let rest = Self::split_header(bytes)?;
+        let chunks = Self::parse_chunks(rest, false)?;
+
+        Ok(Self { chunks })
+    }
+
+    fn split_header(bytes: &[u8]) -> Result<&[u8]> {
+        if bytes.len() < STANDARD_HEADER.len() || bytes[..STANDARD_HEADER.len()] != STANDARD_HEADER
+        {
+            bail!("not a valid PNG: signature mismatch")
+        }
+
+        Ok(&bytes[STANDARD_HEADER.len()..])
+    }
+
+    fn parse_chunks(mut rest: &[u8], verify_crc: bool) -> Result<Vec<Chunk>> {
+        let mut chunks = Vec::new();
+
+        while !rest.is_empty() {
+            let chunk = if verify_crc {
+                Chunk::try_from(rest)?
+            } else {
+                Chunk::try_from_trusted(rest)?
+            };
+            let consumed = 4 + 4 + chunk.length() as usize + 4;
+            chunks.push(chunk);
+            rest = &rest[consumed..];
+        }
+
+        Ok(chunks)
+    }
+
+    /// Appends `chunk`, keeping it ahead of `IEND` when one is present so
+    /// the result still satisfies `from_untrusted_bytes`'s ordering check.
+    pub fn append_chunk(&mut self, chunk: Chunk) {
+        match self
+            .chunks
+            .iter()
+            .position(|c| c.chunk_type().to_string() == "IEND")
+        {
+            Some(position) => self.chunks.insert(position, chunk),
+            None => self.chunks.push(chunk),
+        }
+    }
+
+    pub fn remove_chunk(&mut self, chunk_type: &str) -> Result<Chunk> {
+        let position = self
+            .chunks
+            .iter()
+            .position(|chunk| chunk.chunk_type().to_string() == chunk_type)
+            .ok_or_else(|| anyhow::anyhow!("chunk of type {} not found", chunk_type))?;
+
+        Ok(self.chunks.remove(position))
+    }
+
+    #[cfg(test)]
+    pub fn header(&self) -> &[u8; 8] {
+        &STANDARD_HEADER
+    }
+
+    #[cfg(test)]
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|chunk| chunk.chunk_type().to_string() == chunk_type)
+    }
+
+    /// Returns every chunk of `chunk_type`, in the order they appear in the
+    /// file. Used to reassemble a payload that was split across several
+    /// sequenced chunks.
+    pub fn chunks_by_type(&self, chunk_type: &str) -> Vec<&Chunk> {
+        self.chunks
+            .iter()
+            .filter(|chunk| chunk.chunk_type().to_string() == chunk_type)
+            .collect()
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        STANDARD_HEADER
+            .iter()
+            .copied()
+            .chain(self.chunks.iter().flat_map(|chunk| chunk.as_bytes()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn chunk_from_strings(chunk_type: &str, data: &str) -> Result<Chunk> {
+        let chunk_type = ChunkType::from_str(chunk_type)?;
+        let data: Vec<u8> = data.bytes().collect();
+
+        Ok(Chunk::new(chunk_type, data))
+    }
+
+    fn testing_chunks() -> Vec<Chunk> {
+        vec![
+            chunk_from_strings("IHDR", "I am the header").unwrap(),
+            chunk_from_strings("miDL", "I am a middle chunk").unwrap(),
+            chunk_from_strings("IEND", "I am the end").unwrap(),
+        ]
+    }
+
+    fn testing_png() -> Png {
+        Png::from_chunks(testing_chunks())
+    }
+
+    #[test]
+    fn test_from_chunks() {
+        let chunks = testing_chunks();
+        let png = Png::from_chunks(chunks);
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_valid_png_from_bytes() {
+        let png = testing_png();
+        let bytes = png.as_bytes();
+
+        let png = Png::try_from(bytes.as_ref()).unwrap();
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_invalid_png_signature() {
+        let mut bytes = testing_png().as_bytes();
+        bytes[0] = 0;
+
+        assert!(Png::try_from(bytes.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_invalid_png_chunk() {
+        let mut bytes = testing_png().as_bytes();
+        let last_byte_index = bytes.len() - 1;
+        bytes[last_byte_index] = bytes[last_byte_index].wrapping_add(1);
+
+        assert!(Png::try_from(bytes.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_png_append_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("TESt", "I am a new chunk").unwrap());
+
+        assert!(png.chunk_by_type("TESt").is_some());
+    }
+
+    #[test]
+    fn test_png_remove_chunk() {
+        let mut png = testing_png();
+        png.remove_chunk("miDL").unwrap();
+
+        assert!(png.chunk_by_type("miDL").is_none());
+    }
+
+    #[test]
+    fn test_png_header() {
+        let png = testing_png();
+        assert_eq!(png.header(), &STANDARD_HEADER);
+    }
+
+    #[test]
+    fn test_png_chunks() {
+        let png = testing_png();
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_png_chunk_by_type() {
+        let png = testing_png();
+        let chunk = png.chunk_by_type("miDL").unwrap();
+        assert_eq!(&chunk.chunk_type().to_string(), "miDL");
+        assert_eq!(chunk.data_as_string().unwrap(), "I am a middle chunk");
+    }
+
+    #[test]
+    fn test_png_chunks_by_type() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("miDL", "I am another middle chunk").unwrap());
+
+        let matches = png.chunks_by_type("miDL");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].data_as_string().unwrap(), "I am a middle chunk");
+        assert_eq!(
+            matches[1].data_as_string().unwrap(),
+            "I am another middle chunk"
+        );
+    }
+
+    #[test]
+    fn test_png_as_bytes() {
+        let png = testing_png();
+        let bytes = png.as_bytes();
+        let reconstructed = Png::try_from(bytes.as_ref()).unwrap();
+
+        assert_eq!(png.as_bytes(), reconstructed.as_bytes());
+    }
+
+    #[test]
+    fn test_png_trait_impls() {
+        let png = testing_png();
+        let _png_string = format!("{}", png);
+    }
+
+    #[test]
+    fn test_png_rejects_missing_ihdr() {
+        let chunks = vec![chunk_from_strings("IEND", "I am the end").unwrap()];
+        let bytes = Png::from_chunks(chunks).as_bytes();
+
+        assert!(Png::from_untrusted_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_png_rejects_missing_iend() {
+        let chunks = vec![chunk_from_strings("IHDR", "I am the header").unwrap()];
+        let bytes = Png::from_chunks(chunks).as_bytes();
+
+        assert!(Png::from_untrusted_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_png_from_trusted_bytes_skips_crc_check() {
+        let mut bytes = testing_png().as_bytes();
+        let last_byte_index = bytes.len() - 1;
+        bytes[last_byte_index] = bytes[last_byte_index].wrapping_add(1);
+
+        assert!(Png::from_trusted_bytes(&bytes).is_ok());
+        assert!(Png::from_untrusted_bytes(&bytes).is_err());
+    }
+}